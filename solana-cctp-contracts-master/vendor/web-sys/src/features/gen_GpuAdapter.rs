@@ -0,0 +1,23 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUAdapter , typescript_type = "GPUAdapter")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuAdapter` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUAdapter)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuAdapter`*"]
+    pub type GpuAdapter;
+    #[cfg(feature = "GpuSupportedFeatures")]
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUAdapter" , js_name = features)]
+    #[doc = "Getter for the `features` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUAdapter/features)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuAdapter`, `GpuSupportedFeatures`*"]
+    pub fn features(this: &GpuAdapter) -> GpuSupportedFeatures;
+}
@@ -0,0 +1,24 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+#[doc = "The `GpuBlendFactor` enum."]
+#[doc = ""]
+#[doc = "*This API requires the following crate features to be activated: `GpuBlendFactor`*"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuBlendFactor {
+    Zero = "zero",
+    One = "one",
+    Src = "src",
+    OneMinusSrc = "one-minus-src",
+    SrcAlpha = "src-alpha",
+    OneMinusSrcAlpha = "one-minus-src-alpha",
+    Dst = "dst",
+    OneMinusDst = "one-minus-dst",
+    DstAlpha = "dst-alpha",
+    OneMinusDstAlpha = "one-minus-dst-alpha",
+    SrcAlphaSaturated = "src-alpha-saturated",
+    Constant = "constant",
+    OneMinusConstant = "one-minus-constant",
+}
@@ -0,0 +1,16 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+#[doc = "The `GpuBlendOperation` enum."]
+#[doc = ""]
+#[doc = "*This API requires the following crate features to be activated: `GpuBlendOperation`*"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuBlendOperation {
+    Add = "add",
+    Subtract = "subtract",
+    ReverseSubtract = "reverse-subtract",
+    Min = "min",
+    Max = "max",
+}
@@ -0,0 +1,54 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUBlendState)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuBlendState` dictionary."]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuBlendState`*"]
+    pub type GpuBlendState;
+}
+impl GpuBlendState {
+    #[cfg(feature = "GpuBlendComponent")]
+    #[doc = "Construct a new `GpuBlendState`."]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuBlendComponent`, `GpuBlendState`*"]
+    pub fn new(color: &GpuBlendComponent, alpha: &GpuBlendComponent) -> Self {
+        #[allow(unused_mut)]
+        let mut ret: Self = ::wasm_bindgen::JsCast::unchecked_into(::js_sys::Object::new());
+        ret.color(color);
+        ret.alpha(alpha);
+        ret
+    }
+    #[cfg(feature = "GpuBlendComponent")]
+    #[doc = "Change the `color` field of this object."]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuBlendComponent`, `GpuBlendState`*"]
+    pub fn color(&mut self, val: &GpuBlendComponent) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        let r = ::js_sys::Reflect::set(self.as_ref(), &JsValue::from("color"), &JsValue::from(val));
+        debug_assert!(
+            r.is_ok(),
+            "setting properties should never fail on our dictionary objects"
+        );
+        let _ = r;
+        self
+    }
+    #[cfg(feature = "GpuBlendComponent")]
+    #[doc = "Change the `alpha` field of this object."]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuBlendComponent`, `GpuBlendState`*"]
+    pub fn alpha(&mut self, val: &GpuBlendComponent) -> &mut Self {
+        use wasm_bindgen::JsValue;
+        let r = ::js_sys::Reflect::set(self.as_ref(), &JsValue::from("alpha"), &JsValue::from(val));
+        debug_assert!(
+            r.is_ok(),
+            "setting properties should never fail on our dictionary objects"
+        );
+        let _ = r;
+        self
+    }
+}
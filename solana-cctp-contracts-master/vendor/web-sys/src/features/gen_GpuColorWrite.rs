@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUColorWrite , typescript_type = "GPUColorWrite")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuColorWrite` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUColorWrite)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuColorWrite`*"]
+    pub type GpuColorWrite;
+}
+impl GpuColorWrite {
+    pub const RED: u32 = 0x1;
+    pub const GREEN: u32 = 0x2;
+    pub const BLUE: u32 = 0x4;
+    pub const ALPHA: u32 = 0x8;
+    pub const ALL: u32 = 0xf;
+}
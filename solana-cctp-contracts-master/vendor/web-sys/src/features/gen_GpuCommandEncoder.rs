@@ -0,0 +1,59 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUCommandEncoder , typescript_type = "GPUCommandEncoder")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuCommandEncoder` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCommandEncoder)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCommandEncoder`*"]
+    pub type GpuCommandEncoder;
+    #[cfg(feature = "GpuExtent3dDict")]
+    #[cfg(feature = "GpuImageCopyBuffer")]
+    #[cfg(feature = "GpuImageCopyTexture")]
+    # [wasm_bindgen (method , structural , js_class = "GPUCommandEncoder" , js_name = copyBufferToTexture)]
+    #[doc = "The `copyBufferToTexture()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCommandEncoder/copyBufferToTexture)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCommandEncoder`, `GpuExtent3dDict`, `GpuImageCopyBuffer`, `GpuImageCopyTexture`*"]
+    pub fn copy_buffer_to_texture(
+        this: &GpuCommandEncoder,
+        source: &GpuImageCopyBuffer,
+        destination: &GpuImageCopyTexture,
+        copy_size: &GpuExtent3dDict,
+    );
+    #[cfg(feature = "GpuExtent3dDict")]
+    #[cfg(feature = "GpuImageCopyBuffer")]
+    #[cfg(feature = "GpuImageCopyTexture")]
+    # [wasm_bindgen (method , structural , js_class = "GPUCommandEncoder" , js_name = copyTextureToBuffer)]
+    #[doc = "The `copyTextureToBuffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCommandEncoder/copyTextureToBuffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCommandEncoder`, `GpuExtent3dDict`, `GpuImageCopyBuffer`, `GpuImageCopyTexture`*"]
+    pub fn copy_texture_to_buffer(
+        this: &GpuCommandEncoder,
+        source: &GpuImageCopyTexture,
+        destination: &GpuImageCopyBuffer,
+        copy_size: &GpuExtent3dDict,
+    );
+    #[cfg(feature = "GpuExtent3dDict")]
+    #[cfg(feature = "GpuImageCopyTexture")]
+    # [wasm_bindgen (method , structural , js_class = "GPUCommandEncoder" , js_name = copyTextureToTexture)]
+    #[doc = "The `copyTextureToTexture()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCommandEncoder/copyTextureToTexture)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCommandEncoder`, `GpuExtent3dDict`, `GpuImageCopyTexture`*"]
+    pub fn copy_texture_to_texture(
+        this: &GpuCommandEncoder,
+        source: &GpuImageCopyTexture,
+        destination: &GpuImageCopyTexture,
+        copy_size: &GpuExtent3dDict,
+    );
+}
@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUCompilationInfo , typescript_type = "GPUCompilationInfo")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuCompilationInfo` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationInfo)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationInfo`*"]
+    pub type GpuCompilationInfo;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationInfo" , js_name = messages)]
+    #[doc = "Getter for the `messages` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationInfo/messages)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationInfo`*"]
+    pub fn messages(this: &GpuCompilationInfo) -> ::js_sys::Array;
+}
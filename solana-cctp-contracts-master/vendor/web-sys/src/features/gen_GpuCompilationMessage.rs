@@ -0,0 +1,58 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUCompilationMessage , typescript_type = "GPUCompilationMessage")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuCompilationMessage` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub type GpuCompilationMessage;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = message)]
+    #[doc = "Getter for the `message` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/message)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub fn message(this: &GpuCompilationMessage) -> String;
+    #[cfg(feature = "GpuCompilationMessageType")]
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = type)]
+    #[doc = "Getter for the `type` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/type)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`, `GpuCompilationMessageType`*"]
+    pub fn type_(this: &GpuCompilationMessage) -> GpuCompilationMessageType;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = lineNum)]
+    #[doc = "Getter for the `lineNum` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/lineNum)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub fn line_num(this: &GpuCompilationMessage) -> f64;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = linePos)]
+    #[doc = "Getter for the `linePos` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/linePos)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub fn line_pos(this: &GpuCompilationMessage) -> f64;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = offset)]
+    #[doc = "Getter for the `offset` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/offset)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub fn offset(this: &GpuCompilationMessage) -> f64;
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUCompilationMessage" , js_name = length)]
+    #[doc = "Getter for the `length` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUCompilationMessage/length)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessage`*"]
+    pub fn length(this: &GpuCompilationMessage) -> f64;
+}
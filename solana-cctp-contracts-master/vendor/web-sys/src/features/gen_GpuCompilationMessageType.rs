@@ -0,0 +1,14 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+#[doc = "The `GpuCompilationMessageType` enum."]
+#[doc = ""]
+#[doc = "*This API requires the following crate features to be activated: `GpuCompilationMessageType`*"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuCompilationMessageType {
+    Error = "error",
+    Warning = "warning",
+    Info = "info",
+}
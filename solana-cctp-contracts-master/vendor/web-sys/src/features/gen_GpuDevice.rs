@@ -0,0 +1,35 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUDevice , typescript_type = "GPUDevice")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuDevice` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUDevice)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuDevice`*"]
+    pub type GpuDevice;
+    #[cfg(feature = "GpuSupportedFeatures")]
+    # [wasm_bindgen (structural , method , getter , js_class = "GPUDevice" , js_name = features)]
+    #[doc = "Getter for the `features` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUDevice/features)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuDevice`, `GpuSupportedFeatures`*"]
+    pub fn features(this: &GpuDevice) -> GpuSupportedFeatures;
+    #[cfg(feature = "GpuComputePipeline")]
+    #[cfg(feature = "GpuComputePipelineDescriptor")]
+    # [wasm_bindgen (method , structural , js_class = "GPUDevice" , js_name = createComputePipelineAsync)]
+    #[doc = "The `createComputePipelineAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUDevice/createComputePipelineAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuComputePipeline`, `GpuComputePipelineDescriptor`, `GpuDevice`*"]
+    pub fn create_compute_pipeline_async(
+        this: &GpuDevice,
+        descriptor: &GpuComputePipelineDescriptor,
+    ) -> ::js_sys::Promise;
+}
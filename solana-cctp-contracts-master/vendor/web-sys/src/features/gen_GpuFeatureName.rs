@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+#[doc = "The `GpuFeatureName` enum."]
+#[doc = ""]
+#[doc = "*This API requires the following crate features to be activated: `GpuFeatureName`*"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuFeatureName {
+    DepthClipControl = "depth-clip-control",
+    Depth32floatStencil8 = "depth32float-stencil8",
+    TextureCompressionBc = "texture-compression-bc",
+    TextureCompressionEtc2 = "texture-compression-etc2",
+    TextureCompressionAstc = "texture-compression-astc",
+    TimestampQuery = "timestamp-query",
+    IndirectFirstInstance = "indirect-first-instance",
+    ShaderF16 = "shader-f16",
+    Rg11b10ufloatRenderable = "rg11b10ufloat-renderable",
+    Bgra8unormStorage = "bgra8unorm-storage",
+    Float32Filterable = "float32-filterable",
+}
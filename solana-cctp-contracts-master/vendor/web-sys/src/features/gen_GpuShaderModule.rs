@@ -0,0 +1,23 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUShaderModule , typescript_type = "GPUShaderModule")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuShaderModule` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUShaderModule)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuShaderModule`*"]
+    pub type GpuShaderModule;
+    #[cfg(feature = "GpuCompilationInfo")]
+    # [wasm_bindgen (method , structural , js_class = "GPUShaderModule" , js_name = getCompilationInfo)]
+    #[doc = "The `getCompilationInfo()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUShaderModule/getCompilationInfo)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuCompilationInfo`, `GpuShaderModule`*"]
+    pub fn get_compilation_info(this: &GpuShaderModule) -> ::js_sys::Promise;
+}
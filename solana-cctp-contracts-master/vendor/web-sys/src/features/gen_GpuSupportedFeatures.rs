@@ -0,0 +1,22 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+extern "C" {
+    # [wasm_bindgen (extends = :: js_sys :: Object , js_name = GPUSupportedFeatures , typescript_type = "GPUSupportedFeatures")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[doc = "The `GpuSupportedFeatures` class."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUSupportedFeatures)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuSupportedFeatures`*"]
+    pub type GpuSupportedFeatures;
+    # [wasm_bindgen (method , structural , js_class = "GPUSupportedFeatures" , js_name = has)]
+    #[doc = "The `has()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPUSupportedFeatures/has)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GpuSupportedFeatures`*"]
+    pub fn has(this: &GpuSupportedFeatures, value: &str) -> bool;
+}
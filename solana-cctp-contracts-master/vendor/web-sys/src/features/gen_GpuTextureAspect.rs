@@ -0,0 +1,14 @@
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+use super::*;
+use wasm_bindgen::prelude::*;
+#[wasm_bindgen]
+#[doc = "The `GpuTextureAspect` enum."]
+#[doc = ""]
+#[doc = "*This API requires the following crate features to be activated: `GpuTextureAspect`*"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuTextureAspect {
+    All = "all",
+    StencilOnly = "stencil-only",
+    DepthOnly = "depth-only",
+}
@@ -4,6 +4,7 @@
 // All files in the project carrying such notice may not be copied, modified, or distributed
 // except according to those terms.
 //! Mappings for the contents of d2d1effects_2.h
+use shared::guiddef::GUID;
 DEFINE_GUID!{CLSID_D2D1Contrast,
     0xb648a78a, 0x0ed5, 0x4f80, 0xa9, 0x4a, 0x8e, 0x82, 0x5a, 0xca, 0x6b, 0x77}
 DEFINE_GUID!{CLSID_D2D1RgbToHue,
@@ -38,3 +39,81 @@ DEFINE_GUID!{CLSID_D2D1HighlightsShadows,
     0xcadc8384, 0x323f, 0x4c7e, 0xa3, 0x61, 0x2e, 0x2b, 0x24, 0xdf, 0x6e, 0xe4}
 DEFINE_GUID!{CLSID_D2D1LookupTable3D,
     0x349e0eda, 0x0088, 0x4a79, 0x9c, 0xa3, 0xc7, 0xe3, 0x00, 0x20, 0x20, 0x20}
+/// Enumerates the built-in Direct2D effect CLSIDs declared in `d2d1effects_2.h`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum D2D1Effect {
+    Contrast,
+    RgbToHue,
+    HueToRgb,
+    ChromaKey,
+    Emboss,
+    Exposure,
+    Grayscale,
+    Invert,
+    Posterize,
+    Sepia,
+    Sharpen,
+    Straighten,
+    TemperatureTint,
+    Vignette,
+    EdgeDetection,
+    HighlightsShadows,
+    LookupTable3D,
+}
+impl D2D1Effect {
+    /// Returns the CLSID constant backing this effect.
+    pub fn clsid(&self) -> GUID {
+        match *self {
+            D2D1Effect::Contrast => CLSID_D2D1Contrast,
+            D2D1Effect::RgbToHue => CLSID_D2D1RgbToHue,
+            D2D1Effect::HueToRgb => CLSID_D2D1HueToRgb,
+            D2D1Effect::ChromaKey => CLSID_D2D1ChromaKey,
+            D2D1Effect::Emboss => CLSID_D2D1Emboss,
+            D2D1Effect::Exposure => CLSID_D2D1Exposure,
+            D2D1Effect::Grayscale => CLSID_D2D1Grayscale,
+            D2D1Effect::Invert => CLSID_D2D1Invert,
+            D2D1Effect::Posterize => CLSID_D2D1Posterize,
+            D2D1Effect::Sepia => CLSID_D2D1Sepia,
+            D2D1Effect::Sharpen => CLSID_D2D1Sharpen,
+            D2D1Effect::Straighten => CLSID_D2D1Straighten,
+            D2D1Effect::TemperatureTint => CLSID_D2D1TemperatureTint,
+            D2D1Effect::Vignette => CLSID_D2D1Vignette,
+            D2D1Effect::EdgeDetection => CLSID_D2D1EdgeDetection,
+            D2D1Effect::HighlightsShadows => CLSID_D2D1HighlightsShadows,
+            D2D1Effect::LookupTable3D => CLSID_D2D1LookupTable3D,
+        }
+    }
+    /// Returns every effect declared in this header, in declaration order.
+    pub fn all() -> &'static [D2D1Effect] {
+        static ALL: &[D2D1Effect] = &[
+            D2D1Effect::Contrast,
+            D2D1Effect::RgbToHue,
+            D2D1Effect::HueToRgb,
+            D2D1Effect::ChromaKey,
+            D2D1Effect::Emboss,
+            D2D1Effect::Exposure,
+            D2D1Effect::Grayscale,
+            D2D1Effect::Invert,
+            D2D1Effect::Posterize,
+            D2D1Effect::Sepia,
+            D2D1Effect::Sharpen,
+            D2D1Effect::Straighten,
+            D2D1Effect::TemperatureTint,
+            D2D1Effect::Vignette,
+            D2D1Effect::EdgeDetection,
+            D2D1Effect::HighlightsShadows,
+            D2D1Effect::LookupTable3D,
+        ];
+        ALL
+    }
+}
+impl ::core::convert::TryFrom<GUID> for D2D1Effect {
+    type Error = GUID;
+    fn try_from(clsid: GUID) -> Result<Self, Self::Error> {
+        D2D1Effect::all()
+            .iter()
+            .copied()
+            .find(|effect| effect.clsid() == clsid)
+            .ok_or(clsid)
+    }
+}